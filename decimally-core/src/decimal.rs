@@ -1,6 +1,103 @@
 use crate::error::DecimalStorageError;
 use crate::int::{SignedInteger, UnsignedInteger};
 
+/// The category of a decimal value, modeled on rustc_apfloat's `Category`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Zero,
+    Normal,
+    Infinity,
+    Nan,
+}
+
+/// Rounding modes for lossy conversions and arithmetic, modeled on rustc_apfloat's `Round`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Round {
+    NearestTiesToEven,
+    NearestTiesToAway,
+    TowardZero,
+    TowardPositive,
+    TowardNegative,
+}
+
+/// Errors that can occur when parsing a decimal from a string, modeled on rustc_apfloat's
+/// `ParseError`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input was empty (after stripping an optional leading sign)
+    Empty,
+
+    /// The input contained a character that isn't valid in a decimal literal
+    InvalidDigit,
+
+    /// The exponent part (after `E` and an optional sign) was empty or contained a non-digit
+    InvalidExponent,
+
+    /// The parsed coeffecient or exponent doesn't fit in the representable range
+    OutOfRange,
+}
+
+/// How much precision was lost below the retained digits, combining guard and sticky information
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Loss {
+    ExactlyZero,
+    LessThanHalf,
+    ExactlyHalf,
+    MoreThanHalf,
+}
+
+impl Loss {
+    /// Folds a newly-dropped digit into the loss accumulated from the less significant digits
+    /// dropped so far. Digits must be folded in order from least significant to most significant,
+    /// so that the final call's digit is the one adjacent to the retained coefficient
+    pub fn combine_digit(self, digit: u32) -> Loss {
+        use core::cmp::Ordering;
+
+        let sticky = self != Loss::ExactlyZero;
+        match digit.cmp(&5) {
+            Ordering::Less if digit == 0 && !sticky => Loss::ExactlyZero,
+            Ordering::Less => Loss::LessThanHalf,
+            Ordering::Equal if !sticky => Loss::ExactlyHalf,
+            Ordering::Equal => Loss::MoreThanHalf,
+            Ordering::Greater => Loss::MoreThanHalf,
+        }
+    }
+
+    /// Returns true if a value with this lost fraction should be rounded away from the retained
+    /// digits under `round`, given the retained value's sign and whether its least significant
+    /// retained digit is odd
+    pub fn round_up(self, round: Round, negative: bool, retained_is_odd: bool) -> bool {
+        match round {
+            Round::TowardZero => false,
+            Round::TowardPositive => !negative && self != Loss::ExactlyZero,
+            Round::TowardNegative => negative && self != Loss::ExactlyZero,
+            Round::NearestTiesToAway => matches!(self, Loss::ExactlyHalf | Loss::MoreThanHalf),
+            Round::NearestTiesToEven => match self {
+                Loss::MoreThanHalf => true,
+                Loss::ExactlyHalf => retained_is_odd,
+                _ => false,
+            },
+        }
+    }
+}
+
+/// Status flags describing how a lossy operation's result diverged from the exact value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Status {
+    /// The result was rounded because the exact value could not be represented
+    pub inexact: bool,
+
+    /// The exact value's exponent was too large to represent and was clamped
+    pub overflow: bool,
+}
+
+/// A value paired with the status flags produced while computing it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusAnd<T> {
+    pub status: Status,
+    pub value: T,
+}
+
 pub trait Decimal: Sized {
     type Exponent: SignedInteger;
     type Coeffecient: UnsignedInteger;
@@ -26,12 +123,63 @@ pub trait Decimal: Sized {
     /// Set the decimal coeffecient (signficand)
     fn set_coeffecient(&mut self, coeff: Self::Coeffecient) -> Result<(), DecimalStorageError>;
 
-    /*
-    fn to_scientific_string() {}
-    fn to_engineering_string() {}
-    fn to_string() {}
-    fn from_string() {}
-    */
+    // ----------------------------------------------------
+    // Special Values
+    // ----------------------------------------------------
+
+    /// Get the category (zero, normal, infinity, or NaN) of this decimal
+    fn category(&self) -> Category;
+
+    /// Create a positive or negative infinity. True indicates a negative sign
+    fn infinity(sign: bool) -> Self;
+
+    /// Create a quiet NaN
+    fn nan() -> Self;
+
+    /// Create a signaling NaN
+    fn signaling_nan() -> Self;
+
+    /// Returns true if this value is a NaN (quiet or signaling)
+    fn is_nan(&self) -> bool {
+        self.category() == Category::Nan
+    }
+
+    /// Returns true if this value is positive or negative infinity
+    fn is_infinite(&self) -> bool {
+        self.category() == Category::Infinity
+    }
+
+    /// Returns true if this value is neither infinite nor NaN
+    fn is_finite(&self) -> bool {
+        !self.is_infinite() && !self.is_nan()
+    }
+
+    /// Returns true if this value is a signaling NaN
+    fn is_signaling(&self) -> bool;
+
+    // ----------------------------------------------------
+    // String Conversions
+    // ----------------------------------------------------
+
+    /// Format using the decimal spec's "to-scientific-string" conversion: plain notation when the
+    /// exponent isn't positive and the adjusted exponent (coeffecient digit count - 1 + exponent)
+    /// is -6 or greater, otherwise scientific notation with a single leading digit
+    fn to_scientific_string(&self) -> String;
+
+    /// Format like [`Decimal::to_scientific_string`], but when scientific notation applies the
+    /// exponent is adjusted to a multiple of three, shifting 1-3 leading digits before the point
+    fn to_engineering_string(&self) -> String;
+
+    /// Format using the decimal spec's default string conversion (equivalent to
+    /// `to_scientific_string`)
+    fn to_string(&self) -> String {
+        self.to_scientific_string()
+    }
+
+    /// Parse a decimal from its string representation, preserving the input's exponent (quantum)
+    /// so that e.g. `"1.50"` and `"15E-1"` round-trip to different bit patterns
+    fn from_string(s: &str) -> Result<Self, ParseError>;
+
     fn is_sign_positive(&self) -> bool {
         !self.sign()
     }
@@ -58,8 +206,13 @@ pub trait Decimal: Sized {
     /// Create decimal from `u16` with potential precision loss
     fn from_u16(num: u16) -> Self;
 
-    /// Create decimal from `u32` with potential precision loss
-    fn from_u32(num: u32) -> Self;
+    /// Create decimal from `u32` with potential precision loss, rounding ties to even
+    fn from_u32(num: u32) -> Self {
+        Self::from_u32_rounded(num, Round::NearestTiesToEven).value
+    }
+
+    /// Create decimal from `u32`, rounding with `round` if its digits don't fit the coeffecient
+    fn from_u32_rounded(num: u32, round: Round) -> StatusAnd<Self>;
 
     /*
         /// Create decimal from `u64` with potential precision loss
@@ -0,0 +1,108 @@
+//! A 128-bit floating point decimal using IEEE-754 encoding
+use crate::decimal_impl::DecimalImpl;
+use crate::semantics::Decimal128Semantics;
+
+/// A 128-bit floating point decimal using IEEE-754 encoding
+pub type Decimal128 = DecimalImpl<Decimal128Semantics>;
+
+/// Minimum exponent value
+pub const EXPONENT_MIN: i16 = -6143;
+
+/// Maximum exponent value
+pub const EXPONENT_MAX: i16 = 6144;
+
+/// Maximum representable quantum (raw, stored) exponent. Tighter than [`EXPONENT_MAX`] by
+/// `PRECISION - 1`: the combination field's 2-bit exponent MSB can only be 0, 1, or 2, since 3
+/// collides with the reserved Infinity/NaN patterns
+pub const MAX_QUANTUM_EXPONENT: i16 = 6111; // EXPONENT_MAX - (PRECISION - 1)
+
+/// Maximum coeffecient (significand) value
+pub const COEFFECIENT_MAX: u128 = 9_999_999_999_999_999_999_999_999_999_999_999; // 10 ^ PRECISION - 1
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decimal::{Category, Decimal};
+
+    #[test]
+    fn test_decimal128_sign() {
+        let mut dec = Decimal128::new();
+        assert_eq!(dec.is_sign_positive(), true);
+
+        dec.set_sign(true);
+        assert_eq!(dec.is_sign_negative(), true);
+    }
+
+    #[test]
+    fn test_decimal128_exponent_round_trip() {
+        let mut dec = Decimal128::new();
+
+        assert_eq!(dec.set_exponent(EXPONENT_MIN - 1).is_err(), true);
+        assert_eq!(dec.set_exponent(EXPONENT_MAX + 1).is_err(), true);
+
+        // Everything above MAX_QUANTUM_EXPONENT (including EXPONENT_MAX itself) must be rejected:
+        // encoding it would set the combination field's 2-bit exponent MSB to 3, which collides
+        // with the patterns reserved for Infinity and NaN.
+        for exp in (MAX_QUANTUM_EXPONENT + 1)..=EXPONENT_MAX {
+            assert_eq!(dec.set_exponent(exp).is_err(), true, "expected exp {} to be rejected", exp);
+        }
+
+        for exp in &[EXPONENT_MIN, -1000, 0, 1000, 6000, MAX_QUANTUM_EXPONENT] {
+            let exp = *exp;
+            dec.set_exponent(exp).unwrap();
+            assert_eq!(exp, dec.exponent());
+        }
+    }
+
+    #[test]
+    fn test_decimal128_coeffecient_round_trip() {
+        let mut dec = Decimal128::new();
+
+        assert_eq!(dec.set_coeffecient(COEFFECIENT_MAX + 1).is_err(), true);
+
+        for coeff in &[0, 5, 999, 123_456_789_012_345, COEFFECIENT_MAX] {
+            let coeff = *coeff;
+            dec.set_coeffecient(coeff).unwrap();
+            assert_eq!(coeff, dec.coeffecient());
+        }
+    }
+
+    #[test]
+    fn test_decimal128_category_and_special_values() {
+        assert_eq!(Decimal128::new().category(), Category::Zero);
+        assert_eq!(Decimal128::infinity(false).category(), Category::Infinity);
+        assert_eq!(Decimal128::nan().category(), Category::Nan);
+        assert_eq!(Decimal128::signaling_nan().is_signaling(), true);
+    }
+
+    #[test]
+    fn test_decimal128_to_string() {
+        let mut dec = Decimal128::new();
+        dec.set_exponent(MAX_QUANTUM_EXPONENT).unwrap();
+        dec.set_coeffecient(9).unwrap();
+        assert_eq!(dec.to_scientific_string(), "9E+6111");
+
+        let parsed: Decimal128 = "9E+6111".parse().unwrap();
+        assert_eq!(parsed.coeffecient(), 9);
+        assert_eq!(parsed.exponent(), MAX_QUANTUM_EXPONENT);
+    }
+
+    #[test]
+    fn test_decimal128_ordering_and_cohorts() {
+        let one_e0: Decimal128 = "1E0".parse().unwrap();
+        let ten_e_minus_1: Decimal128 = "10E-1".parse().unwrap();
+        assert_ne!(one_e0.bits, ten_e_minus_1.bits);
+        assert!(one_e0 == ten_e_minus_1);
+        assert_ne!(
+            one_e0.total_cmp(&ten_e_minus_1),
+            core::cmp::Ordering::Equal
+        );
+
+        let neg_one: Decimal128 = "-1".parse().unwrap();
+        let zero = Decimal128::new();
+        assert!(neg_one < zero);
+        assert!(zero < one_e0);
+        assert_eq!(one_e0.same_quantum(&one_e0), true);
+        assert_eq!(one_e0.same_quantum(&ten_e_minus_1), false);
+    }
+}
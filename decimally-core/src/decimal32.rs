@@ -1,239 +1,30 @@
 //! A 32-bit floating point decimal using IEEE-754 encoding
-use crate::decimal::Decimal;
-use crate::dpd::digits_from_dpd;
-use crate::error::DecimalStorageError;
-
-/// Lookup table for converting a 5-bit combination field to the 2 most significant bits of the
-/// exponent
-const COMB_EXP_LOOKUP: [u8; 32] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 0, 0, 1, 1, 2, 2, 3, 3,
-];
-
-/// Lookup table for converting a 5-bit combination field to the most significand digit of the
-/// coeffecient in BCD format (4-bits per digit)
-const COMB_DIG_LOOKUP: [u8; 32] = [
-    0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 8, 9, 8, 9, 0, 1,
-];
-
-const SIGN_MASK: u32 = 0x8000_0000;
-const COMBINATION_MASK: u32 = 0x7c00_0000;
-const EXPONENT_MASK: u32 = 0x03f0_0000;
-const COEFFECIENT_MASK: u32 = 0x000f_ffff;
-
-const PRECISION: usize = 7;
-const EXPONENT_BIAS: i8 = 101;
+use crate::decimal_impl::DecimalImpl;
+use crate::semantics::Decimal32Semantics;
+
+/// A 32-bit floating point decimal using IEEE-754 encoding
+pub type Decimal32 = DecimalImpl<Decimal32Semantics>;
 
 /// Minimum exponent value
-pub const EXPONENT_MIN: i8 = -95;
+pub const EXPONENT_MIN: i16 = -95;
 
 /// Maximum exponent value
-pub const EXPONENT_MAX: i8 = 96;
+pub const EXPONENT_MAX: i16 = 96;
+
+/// Maximum representable quantum (raw, stored) exponent. Tighter than [`EXPONENT_MAX`] by
+/// `PRECISION - 1`: the combination field's 2-bit exponent MSB can only be 0, 1, or 2, since 3
+/// collides with the reserved Infinity/NaN patterns
+pub const MAX_QUANTUM_EXPONENT: i16 = 90; // EXPONENT_MAX - (PRECISION - 1)
 
 /// Maximum coeffecient (significand) value
 pub const COEFFECIENT_MAX: u32 = 9_999_999; // 10 ^ PRECISION - 1
 
-/// Zero decimal (0E1)
-pub const ZERO: u32 = 0x6000_0000;
-
-// Encodes an exponent's 2 most significant bits and a coeffecient's most significant digit in BCD
-// (4-bit) into a 5-bit combination field
-fn encode_combination_field(exp_msb: u8, coeff_msd: u8) -> u8 {
-    let mut comb: u8 = 0;
-    if coeff_msd <= 7 {
-        comb |= (exp_msb << 3) | (coeff_msd & 0x7);
-    } else {
-        comb |= 0x18 | (exp_msb << 1) | (coeff_msd & 0x1);
-    }
-    comb
-}
-
-/// A 32-bit floating point decimal using IEEE-754 encoding
-pub struct Decimal32 {
-    pub bits: u32,
-}
-
-impl Decimal32 {
-    /// Gets the 5-bit combination field
-    #[inline]
-    fn combination_field(&self) -> u8 {
-        ((self.bits & COMBINATION_MASK) >> 26) as u8
-    }
-
-    /// Sets the 5-bit combination field
-    #[inline]
-    fn set_combination_field(&mut self, comb: u8) {
-        self.bits &= !COMBINATION_MASK;
-        self.bits |= (u32::from(comb)) << 26;
-    }
-
-    /// Gets the 2-bit exponent MSB from the combination field using a lookup table
-    #[inline]
-    fn exponent_msb(&self) -> u8 {
-        COMB_EXP_LOOKUP[self.combination_field() as usize]
-    }
-
-    /// Gets the 6-bit exponent continuation
-    #[inline]
-    fn exponent_cont(&self) -> u8 {
-        ((self.bits & EXPONENT_MASK) >> 20) as u8
-    }
-
-    /// Sets the 6-bit exponent continutation
-    #[inline]
-    fn set_exponent_cont(&mut self, cont: u8) {
-        self.bits &= !EXPONENT_MASK;
-        self.bits |= (u32::from(cont)) << 20;
-    }
-
-    /// Gets the 4-bit (BCD) coeffecient MSB from the combination field using a lookup table
-    #[inline]
-    fn coeffecient_msd(&self) -> u8 {
-        COMB_DIG_LOOKUP[self.combination_field() as usize]
-    }
-
-    /// Gets the 20-bit (DPD encoded) coeffecient continuaton
-    #[inline]
-    fn coeffecient_cont(&self) -> u32 {
-        self.bits & COEFFECIENT_MASK
-    }
-
-    /// Sets the 20-bit (DPD encoded) coeffecient continuation
-    #[inline]
-    fn set_coeffecient_cont(&mut self, cont: u32) {
-        self.bits &= !COEFFECIENT_MASK;
-        self.bits |= cont;
-    }
-}
-
-impl Decimal for Decimal32 {
-    type Coeffecient = u32;
-    type Exponent = i8;
-
-    fn new() -> Decimal32 {
-        Decimal32 { bits: ZERO }
-    }
-
-    fn sign(&self) -> bool {
-        (self.bits >> 31) > 0
-    }
-
-    fn set_sign(&mut self, sign: bool) {
-        let sign: u32 = if sign { 1 } else { 0 };
-        self.bits &= !SIGN_MASK;
-        self.bits |= sign << 31;
-    }
-
-    fn exponent(&self) -> Self::Exponent {
-        // Get exponent parts (2-bit msb & 6-bit continuation)
-        let exp_msb = self.exponent_msb();
-        let exp_cont = self.exponent_cont();
-
-        // Encoded exponent as u8
-        let encoded_exp = (exp_msb << 6) + (exp_cont as u8);
-
-        // Adjust encoded exponent with bias
-        // Note: Uses intermediate i16 to prevent u8 underflow
-        let exp = i16::from(encoded_exp) - i16::from(EXPONENT_BIAS);
-
-        exp as i8
-    }
-
-    fn set_exponent(&mut self, exp: Self::Exponent) -> Result<(), DecimalStorageError> {
-        if exp > EXPONENT_MAX {
-            return Err(DecimalStorageError::ExponentTooLarge);
-        }
-
-        if exp < EXPONENT_MIN {
-            return Err(DecimalStorageError::ExponentTooSmall);
-        }
-
-        // Add the exponent bias
-        // Note: Uses intermediate i16 to prevent u8 underflow
-        let exp = (i16::from(exp) + i16::from(EXPONENT_BIAS)) as u8;
-
-        // Set new exponent msb in combination field
-        let exp_msb = exp >> 6;
-        let coeff_msd = self.coeffecient_msd() as u8;
-        let combination_field = encode_combination_field(exp_msb, coeff_msd);
-        self.set_combination_field(combination_field);
-
-        // Set new exponent continuation bits
-        let exp_cont = exp & 0x6f;
-        self.set_exponent_cont(exp_cont);
-
-        Ok(())
-    }
-
-    fn coeffecient(&self) -> Self::Coeffecient {
-        let coeff_msd = self.coeffecient_msd();
-        let coeff_cont = self.coeffecient_cont();
-
-        // Unpack coeffecient digits from DPD
-        if coeff_msd > 0 {
-            let coeff = (u32::from(coeff_msd) << 20) | coeff_cont;
-            return digits_from_dpd(coeff, 3);
-        }
-
-        if coeff_cont == 0 {
-            return 0;
-        }
-
-        if coeff_cont == 0x000f_fc00 {
-            return digits_from_dpd(coeff_cont, 2);
-        }
-
-        digits_from_dpd(coeff_cont, 1)
-    }
-
-    fn set_coeffecient(&mut self, coeff: Self::Coeffecient) -> Result<(), DecimalStorageError> {
-        if coeff > COEFFECIENT_MAX {
-            return Err(DecimalStorageError::CoeffecientTooLarge);
-        }
-
-        // TODO:
-        // - Encode coeffecient into dpd
-        // - Get MSD + EXP MSB
-        // - Set MSD into combo field
-        // - Set coeffecient cont
-
-        Ok(())
-    }
-
-    fn from_u8(num: u8) -> Self {
-        let mut d = Self::new();
-        d.set_coeffecient(u32::from(num)).unwrap();
-        d
-    }
-
-    fn from_u16(num: u16) -> Self {
-        let mut d = Self::new();
-        d.set_coeffecient(u32::from(num)).unwrap();
-        d
-    }
-
-    fn from_u32(num: u32) -> Self {
-        let mut d = Self::new();
-        // TODO: How to handle error, clamp or infinity
-        // Spec seems to indicate that the number should be rounded based on user preference
-        // So 4,294,967,295 would need to be truncated to 7 digits by rounding to 4,294,967,000
-        // using the specified rounding system and then representing with different exponent
-        // Question that is raised: Should this be handled implicity or provided to function
-        // by function just like every operation?
-        // Answer, should use context precision UNLESS it's greater than implementation precision,
-        // then use implementation precision
-
-        d.set_coeffecient(u32::from(num)).unwrap();
-        d
-    }
-
-    fn from_u8_checked(num: u8) -> Option<Self> {
-        Some(Self::from_u8(num))
-    }
-}
-
 #[cfg(test)]
 mod tests {
+    use core::cmp::Ordering;
+
     use super::*;
+    use crate::decimal::{Category, Decimal, ParseError, Round};
 
     #[test]
     fn test_decimal32_sign() {
@@ -270,7 +61,14 @@ mod tests {
         assert_eq!(dec.set_exponent(EXPONENT_MIN - 1).is_err(), true);
         assert_eq!(dec.set_exponent(EXPONENT_MAX + 1).is_err(), true);
 
-        for exp in &[EXPONENT_MIN, -5, 0, 5, EXPONENT_MAX] {
+        // Everything above MAX_QUANTUM_EXPONENT (including EXPONENT_MAX itself) must be rejected:
+        // encoding it would set the combination field's 2-bit exponent MSB to 3, which collides
+        // with the 11110/11111 patterns reserved for Infinity and NaN.
+        for exp in (MAX_QUANTUM_EXPONENT + 1)..=EXPONENT_MAX {
+            assert_eq!(dec.set_exponent(exp).is_err(), true, "expected exp {} to be rejected", exp);
+        }
+
+        for exp in &[EXPONENT_MIN, -5, 0, 5, MAX_QUANTUM_EXPONENT] {
             let exp = *exp;
             dec.set_exponent(exp).unwrap();
             assert_eq!(exp, dec.exponent());
@@ -290,4 +88,368 @@ mod tests {
             assert_eq!(coeff, dec.coeffecient());
         }
     }
+
+    #[test]
+    fn test_decimal32_category() {
+        let zero = Decimal32::new();
+        assert_eq!(zero.category(), Category::Zero);
+        assert_eq!(zero.is_finite(), true);
+
+        let mut normal = Decimal32::new();
+        normal.set_coeffecient(5).unwrap();
+        assert_eq!(normal.category(), Category::Normal);
+        assert_eq!(normal.is_finite(), true);
+
+        let pos_inf = Decimal32::infinity(false);
+        assert_eq!(pos_inf.category(), Category::Infinity);
+        assert_eq!(pos_inf.is_infinite(), true);
+        assert_eq!(pos_inf.is_finite(), false);
+        assert_eq!(pos_inf.is_sign_positive(), true);
+
+        let neg_inf = Decimal32::infinity(true);
+        assert_eq!(neg_inf.category(), Category::Infinity);
+        assert_eq!(neg_inf.is_sign_negative(), true);
+
+        let quiet_nan = Decimal32::nan();
+        assert_eq!(quiet_nan.category(), Category::Nan);
+        assert_eq!(quiet_nan.is_nan(), true);
+        assert_eq!(quiet_nan.is_signaling(), false);
+        assert_eq!(quiet_nan.is_finite(), false);
+
+        let signaling_nan = Decimal32::signaling_nan();
+        assert_eq!(signaling_nan.category(), Category::Nan);
+        assert_eq!(signaling_nan.is_nan(), true);
+        assert_eq!(signaling_nan.is_signaling(), true);
+    }
+
+    #[test]
+    fn test_decimal32_special_value_setters_rejected() {
+        use crate::error::DecimalStorageError;
+
+        let mut pos_inf = Decimal32::infinity(false);
+        assert_eq!(
+            pos_inf.set_coeffecient(1_234_567),
+            Err(DecimalStorageError::ValueIsSpecial)
+        );
+        assert_eq!(pos_inf.category(), Category::Infinity);
+
+        let mut neg_inf = Decimal32::infinity(true);
+        assert_eq!(
+            neg_inf.set_exponent(5),
+            Err(DecimalStorageError::ValueIsSpecial)
+        );
+        assert_eq!(neg_inf.category(), Category::Infinity);
+
+        let mut nan = Decimal32::nan();
+        assert_eq!(
+            nan.set_exponent(5),
+            Err(DecimalStorageError::ValueIsSpecial)
+        );
+        assert_eq!(
+            nan.set_coeffecient(42),
+            Err(DecimalStorageError::ValueIsSpecial)
+        );
+        assert_eq!(nan.category(), Category::Nan);
+
+        let mut signaling_nan = Decimal32::signaling_nan();
+        assert_eq!(
+            signaling_nan.set_exponent(5),
+            Err(DecimalStorageError::ValueIsSpecial)
+        );
+        assert_eq!(signaling_nan.category(), Category::Nan);
+        assert_eq!(signaling_nan.is_signaling(), true);
+    }
+
+    #[test]
+    fn test_decimal32_from_u32_rounded() {
+        // Fits as-is: no digits dropped, nothing lost
+        let exact = Decimal32::from_u32_rounded(1_234_567, Round::NearestTiesToEven);
+        assert_eq!(exact.status.inexact, false);
+        assert_eq!(exact.value.coeffecient(), 1_234_567);
+        assert_eq!(exact.value.exponent(), 0);
+
+        // Dropped digit is below half: rounds down under round-to-nearest
+        let below_half = Decimal32::from_u32_rounded(12_345_671, Round::NearestTiesToEven);
+        assert_eq!(below_half.status.inexact, true);
+        assert_eq!(below_half.value.coeffecient(), 1_234_567);
+        assert_eq!(below_half.value.exponent(), 1);
+
+        // Dropped digit is above half: rounds up under round-to-nearest
+        let above_half = Decimal32::from_u32_rounded(12_345_679, Round::NearestTiesToEven);
+        assert_eq!(above_half.value.coeffecient(), 1_234_568);
+        assert_eq!(above_half.value.exponent(), 1);
+
+        // Exactly half: ties-to-even rounds toward the even retained digit
+        let tie_even_down = Decimal32::from_u32_rounded(12_345_625, Round::NearestTiesToEven);
+        assert_eq!(tie_even_down.value.coeffecient(), 1_234_562);
+        assert_eq!(tie_even_down.value.exponent(), 1);
+
+        let tie_even_up = Decimal32::from_u32_rounded(12_345_635, Round::NearestTiesToEven);
+        assert_eq!(tie_even_up.value.coeffecient(), 1_234_564);
+        assert_eq!(tie_even_up.value.exponent(), 1);
+
+        // A tie always rounds away from zero under NearestTiesToAway, even toward an odd digit
+        let tie_away = Decimal32::from_u32_rounded(12_345_625, Round::NearestTiesToAway);
+        assert_eq!(tie_away.value.coeffecient(), 1_234_563);
+        assert_eq!(tie_away.value.exponent(), 1);
+
+        // TowardZero always truncates
+        let toward_zero = Decimal32::from_u32_rounded(12_345_679, Round::TowardZero);
+        assert_eq!(toward_zero.value.coeffecient(), 1_234_567);
+        assert_eq!(toward_zero.value.exponent(), 1);
+
+        // Rounding up can carry into an extra digit, which must be re-normalized
+        let carry = Decimal32::from_u32_rounded(99_999_995, Round::NearestTiesToEven);
+        assert_eq!(carry.value.coeffecient(), 1_000_000);
+        assert_eq!(carry.value.exponent(), 2);
+
+        assert_eq!(Decimal32::from_u32(1_234_567).coeffecient(), 1_234_567);
+    }
+
+    #[test]
+    fn test_decimal32_to_scientific_string() {
+        assert_eq!(Decimal32::new().to_scientific_string(), "0E-101");
+        assert_eq!(Decimal32::from_u32(0).to_scientific_string(), "0");
+        assert_eq!(Decimal32::from_u32(1_234).to_scientific_string(), "1234");
+
+        let mut point5 = Decimal32::new();
+        point5.set_exponent(-1).unwrap();
+        point5.set_coeffecient(5).unwrap();
+        assert_eq!(point5.to_scientific_string(), "0.5");
+
+        let mut small = Decimal32::new();
+        small.set_exponent(-10).unwrap();
+        small.set_coeffecient(5).unwrap();
+        assert_eq!(small.to_scientific_string(), "5E-10");
+
+        let mut large = Decimal32::new();
+        large.set_sign(true);
+        large.set_exponent(2).unwrap();
+        large.set_coeffecient(1_234).unwrap();
+        assert_eq!(large.to_scientific_string(), "-1.234E+5");
+
+        assert_eq!(Decimal32::infinity(false).to_scientific_string(), "Infinity");
+        assert_eq!(
+            Decimal32::infinity(true).to_scientific_string(),
+            "-Infinity"
+        );
+        assert_eq!(Decimal32::nan().to_scientific_string(), "NaN");
+        assert_eq!(Decimal32::signaling_nan().to_scientific_string(), "sNaN");
+    }
+
+    #[test]
+    fn test_decimal32_to_engineering_string() {
+        let mut plain = Decimal32::new();
+        plain.set_exponent(-1).unwrap();
+        plain.set_coeffecient(5).unwrap();
+        assert_eq!(plain.to_engineering_string(), "0.5");
+
+        let mut shifted = Decimal32::new();
+        shifted.set_exponent(2).unwrap();
+        shifted.set_coeffecient(1_234).unwrap();
+        assert_eq!(shifted.to_engineering_string(), "123.4E+3");
+
+        let mut padded = Decimal32::new();
+        padded.set_exponent(5).unwrap();
+        padded.set_coeffecient(1).unwrap();
+        assert_eq!(padded.to_engineering_string(), "100E+3");
+
+        let mut tiny = Decimal32::new();
+        tiny.set_exponent(-10).unwrap();
+        tiny.set_coeffecient(5).unwrap();
+        assert_eq!(tiny.to_engineering_string(), "500E-12");
+    }
+
+    #[test]
+    fn test_decimal32_from_string() {
+        let dotted = Decimal32::from_string("1.50").unwrap();
+        assert_eq!(dotted.coeffecient(), 150);
+        assert_eq!(dotted.exponent(), -2);
+
+        let exponent_form = Decimal32::from_string("15E-1").unwrap();
+        assert_eq!(exponent_form.coeffecient(), 15);
+        assert_eq!(exponent_form.exponent(), -1);
+
+        // Numerically equal, but distinct quanta preserved from the input
+        assert_ne!(dotted.bits, exponent_form.bits);
+
+        let negative = Decimal32::from_string("-42").unwrap();
+        assert_eq!(negative.sign(), true);
+        assert_eq!(negative.coeffecient(), 42);
+        assert_eq!(negative.exponent(), 0);
+
+        let plus_exp = Decimal32::from_string("3E+2").unwrap();
+        assert_eq!(plus_exp.coeffecient(), 3);
+        assert_eq!(plus_exp.exponent(), 2);
+
+        assert_eq!(
+            Decimal32::from_string("Infinity").unwrap().category(),
+            Category::Infinity
+        );
+        assert_eq!(
+            Decimal32::from_string("-inf").unwrap().is_sign_negative(),
+            true
+        );
+        assert_eq!(
+            Decimal32::from_string("NaN").unwrap().category(),
+            Category::Nan
+        );
+        assert_eq!(
+            Decimal32::from_string("sNaN").unwrap().is_signaling(),
+            true
+        );
+
+        assert_parse_err("", ParseError::Empty);
+        assert_parse_err("-", ParseError::Empty);
+        assert_parse_err("1.2.3", ParseError::InvalidDigit);
+        assert_parse_err("abc", ParseError::InvalidDigit);
+        assert_parse_err("1E", ParseError::InvalidExponent);
+        assert_parse_err("99999999999", ParseError::OutOfRange);
+        assert_parse_err("1E999", ParseError::OutOfRange);
+
+        // Just past MAX_QUANTUM_EXPONENT must be rejected rather than silently misparsed: encoding
+        // it would set the combination field's 2-bit exponent MSB to 3, which collides with the
+        // reserved Infinity/NaN patterns.
+        assert_parse_err("9E+91", ParseError::OutOfRange);
+
+        let at_bound = Decimal32::from_string("9E+90").unwrap();
+        assert_eq!(at_bound.coeffecient(), 9);
+        assert_eq!(at_bound.exponent(), 90);
+    }
+
+    /// Asserts that parsing `s` fails with `expected`, without requiring `Decimal32: Debug`
+    fn assert_parse_err(s: &str, expected: ParseError) {
+        match Decimal32::from_string(s) {
+            Err(e) => assert_eq!(e, expected),
+            Ok(_) => panic!("expected {:?} parsing {:?}", expected, s),
+        }
+    }
+
+    #[test]
+    fn test_decimal32_display_and_from_str() {
+        let dec: Decimal32 = "2.50".parse().unwrap();
+        assert_eq!(dec.coeffecient(), 250);
+        assert_eq!(format!("{}", dec), "2.50");
+
+        match "abc".parse::<Decimal32>() {
+            Err(e) => assert_eq!(e, ParseError::InvalidDigit),
+            Ok(_) => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_decimal32_equality() {
+        let pos_zero: Decimal32 = "0".parse().unwrap();
+        let neg_zero: Decimal32 = "-0".parse().unwrap();
+        assert!(pos_zero == neg_zero);
+
+        // Same numeric value, different exponent (a "cohort"): still equal
+        let one_e0: Decimal32 = "1E0".parse().unwrap();
+        let ten_e_minus_1: Decimal32 = "10E-1".parse().unwrap();
+        assert_ne!(one_e0.bits, ten_e_minus_1.bits);
+        assert!(one_e0 == ten_e_minus_1);
+
+        assert!(Decimal32::infinity(false) == Decimal32::infinity(false));
+        assert!(Decimal32::infinity(false) != Decimal32::infinity(true));
+
+        // NaN is never equal to anything, including itself
+        let nan = Decimal32::nan();
+        assert!(nan != Decimal32::nan());
+        assert!(nan != nan);
+        assert_eq!(nan.partial_cmp(&nan), None);
+    }
+
+    #[test]
+    fn test_decimal32_ordering() {
+        let neg_one: Decimal32 = "-1".parse().unwrap();
+        let zero: Decimal32 = "0".parse().unwrap();
+        let one: Decimal32 = "1".parse().unwrap();
+        let two: Decimal32 = "2".parse().unwrap();
+
+        assert!(neg_one < zero);
+        assert!(zero < one);
+        assert!(one < two);
+        assert!(neg_one < two);
+
+        // Infinities anchor the ends, zeros and cohorts compare by value
+        assert!(Decimal32::infinity(true) < neg_one);
+        assert!(two < Decimal32::infinity(false));
+        assert!(Decimal32::infinity(true) < Decimal32::infinity(false));
+
+        let hundred: Decimal32 = "100".parse().unwrap();
+        let one_hundred_e0: Decimal32 = "1E2".parse().unwrap();
+        assert_ne!(hundred.bits, one_hundred_e0.bits);
+        assert_eq!(hundred.partial_cmp(&one_hundred_e0), Some(Ordering::Equal));
+
+        let neg_two: Decimal32 = "-2".parse().unwrap();
+        assert!(neg_two < neg_one);
+
+        // NaN is unordered with everything, including itself
+        let nan = Decimal32::nan();
+        assert_eq!(nan.partial_cmp(&zero), None);
+        assert_eq!(zero.partial_cmp(&nan), None);
+        assert_eq!(nan.partial_cmp(&nan), None);
+    }
+
+    #[test]
+    fn test_decimal32_total_cmp() {
+        // Cohorts compare equal under PartialOrd, but total_cmp orders them deterministically
+        let one_e0: Decimal32 = "1E0".parse().unwrap();
+        let ten_e_minus_1: Decimal32 = "10E-1".parse().unwrap();
+        assert!(one_e0 == ten_e_minus_1);
+        assert_ne!(one_e0.total_cmp(&ten_e_minus_1), Ordering::Equal);
+        assert_eq!(
+            one_e0.total_cmp(&ten_e_minus_1),
+            ten_e_minus_1.total_cmp(&one_e0).reverse()
+        );
+        assert_eq!(one_e0.total_cmp(&one_e0), Ordering::Equal);
+
+        // +0 and -0 compare equal under PartialEq but are distinguished by total_cmp
+        let pos_zero: Decimal32 = "0".parse().unwrap();
+        let neg_zero: Decimal32 = "-0".parse().unwrap();
+        assert!(pos_zero == neg_zero);
+        assert_eq!(neg_zero.total_cmp(&pos_zero), Ordering::Less);
+
+        // NaN sorts outside the finite/infinite range, ordered by sign
+        let pos_nan = Decimal32::nan();
+        let neg_nan = {
+            let mut n = Decimal32::nan();
+            n.set_sign(true);
+            n
+        };
+        assert_eq!(neg_nan.total_cmp(&pos_nan), Ordering::Less);
+        assert_eq!(
+            Decimal32::infinity(false).total_cmp(&pos_nan),
+            Ordering::Less
+        );
+        assert_eq!(
+            neg_nan.total_cmp(&Decimal32::infinity(true)),
+            Ordering::Less
+        );
+        assert_eq!(pos_nan.total_cmp(&pos_nan), Ordering::Equal);
+
+        let signaling = Decimal32::signaling_nan();
+        let quiet = Decimal32::nan();
+        assert_eq!(signaling.total_cmp(&quiet), Ordering::Less);
+    }
+
+    #[test]
+    fn test_decimal32_same_quantum() {
+        let one_e0: Decimal32 = "1E0".parse().unwrap();
+        let ten_e_minus_1: Decimal32 = "10E-1".parse().unwrap();
+        assert!(one_e0 == ten_e_minus_1);
+        assert_eq!(one_e0.same_quantum(&ten_e_minus_1), false);
+        assert_eq!(one_e0.same_quantum(&one_e0), true);
+
+        let also_e0: Decimal32 = "2E0".parse().unwrap();
+        assert_eq!(one_e0.same_quantum(&also_e0), true);
+
+        assert_eq!(
+            Decimal32::infinity(false).same_quantum(&Decimal32::infinity(true)),
+            true
+        );
+        assert_eq!(Decimal32::nan().same_quantum(&Decimal32::signaling_nan()), true);
+        assert_eq!(Decimal32::nan().same_quantum(&one_e0), false);
+        assert_eq!(Decimal32::infinity(false).same_quantum(&one_e0), false);
+    }
 }
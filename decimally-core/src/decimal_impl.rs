@@ -0,0 +1,769 @@
+//! A width-generic IEEE-754 decimal interchange format, parameterized by [`DecimalSemantics`].
+//! The bit-field layout (combination field, exponent continuation, DPD-packed coeffecient
+//! continuation) and the [`Decimal`] impl are written once here; `Decimal32`, `Decimal64`, and
+//! `Decimal128` are thin type aliases over this with their own semantics.
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::decimal::{Category, Decimal, Loss, ParseError, Round, Status, StatusAnd};
+use crate::dpd::{digits_from_dpd, dpd_from_digits};
+use crate::error::DecimalStorageError;
+use crate::int::DecimalStorage;
+use crate::semantics::DecimalSemantics;
+
+/// Lookup table for converting a 5-bit combination field to the 2 most significant bits of the
+/// exponent
+const COMB_EXP_LOOKUP: [u8; 32] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 0, 0, 1, 1, 2, 2, 3, 3,
+];
+
+/// Lookup table for converting a 5-bit combination field to the most significand digit of the
+/// coeffecient in BCD format (4-bits per digit)
+const COMB_DIG_LOOKUP: [u8; 32] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 8, 9, 8, 9, 0, 1,
+];
+
+/// Combination field pattern (11110) that marks a value as infinite
+const INFINITY_COMBINATION: u8 = 0x1e;
+
+/// Combination field pattern (11111) that marks a value as NaN
+const NAN_COMBINATION: u8 = 0x1f;
+
+// Encodes an exponent's 2 most significant bits and a coeffecient's most significant digit in BCD
+// (4-bit) into a 5-bit combination field
+fn encode_combination_field(exp_msb: u8, coeff_msd: u8) -> u8 {
+    let mut comb: u8 = 0;
+    if coeff_msd <= 7 {
+        comb |= (exp_msb << 3) | (coeff_msd & 0x7);
+    } else {
+        comb |= 0x18 | (exp_msb << 1) | (coeff_msd & 0x1);
+    }
+    comb
+}
+
+/// A decimal floating point value using IEEE-754 interchange encoding, generic over its width's
+/// [`DecimalSemantics`]
+pub struct DecimalImpl<S: DecimalSemantics> {
+    pub bits: S::Storage,
+}
+
+impl<S: DecimalSemantics> DecimalImpl<S> {
+    /// Number of DPD declets (each packing 3 decimal digits into 10 bits) in the coeffecient's
+    /// trailing significand, i.e. every digit but the most significant
+    fn declets() -> u32 {
+        ((S::PRECISION - 1) / 3) as u32
+    }
+
+    /// Width in bits of the DPD-packed coeffecient continuation field
+    fn coeffecient_cont_bits() -> u32 {
+        Self::declets() * 10
+    }
+
+    /// Width in bits of the exponent continuation field
+    fn exponent_cont_bits() -> u32 {
+        S::COMBINATION_SHIFT - Self::coeffecient_cont_bits()
+    }
+
+    /// Builds a `bits`-wide mask of 1s starting at the least significant bit
+    fn low_mask(bits: u32) -> S::Storage {
+        (S::Storage::from(1) << bits) - S::Storage::from(1)
+    }
+
+    /// Gets the 5-bit combination field
+    #[inline]
+    fn combination_field(&self) -> u8 {
+        let shifted = self.bits >> S::COMBINATION_SHIFT;
+        (shifted & Self::low_mask(5)).low_u32() as u8
+    }
+
+    /// Sets the 5-bit combination field
+    #[inline]
+    fn set_combination_field(&mut self, comb: u8) {
+        let mask = Self::low_mask(5) << S::COMBINATION_SHIFT;
+        self.bits = self.bits & !mask;
+        self.bits = self.bits | (S::Storage::from(u32::from(comb)) << S::COMBINATION_SHIFT);
+    }
+
+    /// Gets the 2-bit exponent MSB from the combination field using a lookup table
+    #[inline]
+    fn exponent_msb(&self) -> u8 {
+        COMB_EXP_LOOKUP[self.combination_field() as usize]
+    }
+
+    /// Gets the exponent continuation field
+    #[inline]
+    fn exponent_cont(&self) -> u32 {
+        let shifted = self.bits >> Self::coeffecient_cont_bits();
+        (shifted & Self::low_mask(Self::exponent_cont_bits())).low_u32()
+    }
+
+    /// Sets the exponent continutation field
+    #[inline]
+    fn set_exponent_cont(&mut self, cont: u32) {
+        let shift = Self::coeffecient_cont_bits();
+        let mask = Self::low_mask(Self::exponent_cont_bits()) << shift;
+        self.bits = self.bits & !mask;
+        self.bits = self.bits | (S::Storage::from(cont) << shift);
+    }
+
+    /// Gets the 4-bit (BCD) coeffecient MSB from the combination field using a lookup table
+    #[inline]
+    fn coeffecient_msd(&self) -> u8 {
+        COMB_DIG_LOOKUP[self.combination_field() as usize]
+    }
+
+    /// Gets the DPD-packed coeffecient continuation
+    #[inline]
+    fn coeffecient_cont(&self) -> S::Storage {
+        self.bits & Self::low_mask(Self::coeffecient_cont_bits())
+    }
+
+    /// Sets the DPD-packed coeffecient continuation
+    #[inline]
+    fn set_coeffecient_cont(&mut self, cont: S::Storage) {
+        let mask = Self::low_mask(Self::coeffecient_cont_bits());
+        self.bits = self.bits & !mask;
+        self.bits = self.bits | cont;
+    }
+
+    /// Returns true if the combination field marks this value as an infinity or a NaN
+    #[inline]
+    fn is_special(&self) -> bool {
+        self.combination_field() >> 1 == INFINITY_COMBINATION >> 1
+    }
+
+    /// Bit position of the signaling-vs-quiet bit, immediately below the combination field
+    #[inline]
+    fn signaling_shift() -> u32 {
+        S::COMBINATION_SHIFT - 1
+    }
+
+    /// Maximum representable coeffecient value (`10^PRECISION - 1`)
+    fn coeffecient_max() -> S::Coeffecient {
+        let mut max = S::Coeffecient::from(1);
+        for _ in 0..S::PRECISION {
+            max = max * S::Coeffecient::from(10);
+        }
+        max - S::Coeffecient::from(1)
+    }
+
+    /// Maximum representable quantum (raw, stored) exponent. This is tighter than
+    /// [`DecimalSemantics::EXPONENT_MAX`], the IEEE-754 *adjusted* exponent bound: the combination
+    /// field's 2-bit exponent MSB can only be 0, 1, or 2, since 3 collides with the reserved
+    /// `INFINITY_COMBINATION`/`NAN_COMBINATION` patterns, so the raw exponent must additionally
+    /// leave room for the coeffecient's other `PRECISION - 1` digits
+    fn max_quantum_exponent() -> i16 {
+        S::EXPONENT_MAX - (S::PRECISION as i16 - 1)
+    }
+
+    /// Orders two non-NaN values by sign and then by magnitude. Values that numerically differ only
+    /// by cohort (e.g. `1.0` stored as `10E-1` vs `1E0`) compare `Equal` here, matching
+    /// [`PartialOrd`]; see [`DecimalImpl::total_cmp`] for a total order that breaks that tie
+    fn cmp_value(&self, other: &Self) -> Ordering {
+        let self_zero = self.category() == Category::Zero;
+        let other_zero = other.category() == Category::Zero;
+        if self_zero && other_zero {
+            return Ordering::Equal;
+        }
+
+        // Differing signs (with not both operands zero) fully determine the order: a zero's own
+        // sign bit only matters when the other operand is zero too, handled above
+        if self.sign() != other.sign() {
+            return if self.sign() {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+
+        let magnitude = match (self.category(), other.category()) {
+            (Category::Infinity, Category::Infinity) => Ordering::Equal,
+            (Category::Infinity, _) => Ordering::Greater,
+            (_, Category::Infinity) => Ordering::Less,
+            (Category::Zero, _) => Ordering::Less,
+            (_, Category::Zero) => Ordering::Greater,
+            (Category::Normal, Category::Normal) => compare_normals(self, other),
+            (Category::Nan, _) | (_, Category::Nan) => unreachable!("NaN excluded by caller"),
+        };
+
+        if self.sign() {
+            magnitude.reverse()
+        } else {
+            magnitude
+        }
+    }
+
+    /// An IEEE `total_cmp`-style total order: unlike [`PartialOrd`], this never returns `None` for
+    /// NaNs (ordering them by sign, then signaling-ness, then payload) and never treats two
+    /// differently-encoded cohorts of the same numeric value as equal (breaking that tie by
+    /// exponent), so it's suitable for sorting or deduplicating by bit pattern
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        match (self.is_nan(), other.is_nan()) {
+            (true, true) => self.total_cmp_nan(other),
+            (true, false) => {
+                if self.sign() {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }
+            }
+            (false, true) => {
+                if other.sign() {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                }
+            }
+            (false, false) => self.total_cmp_finite(other),
+        }
+    }
+
+    /// Orders two NaNs by sign, then signaling-ness (signaling before quiet), then payload
+    fn total_cmp_nan(&self, other: &Self) -> Ordering {
+        if self.sign() != other.sign() {
+            return if self.sign() {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+
+        let rank = match (self.is_signaling(), other.is_signaling()) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            _ => self.coeffecient().cmp(&other.coeffecient()),
+        };
+
+        if self.sign() {
+            rank.reverse()
+        } else {
+            rank
+        }
+    }
+
+    /// Orders two non-NaN values, breaking [`DecimalImpl::cmp_value`]'s ties between differently
+    /// signed zeros and between cohorts of the same zero or normal value
+    fn total_cmp_finite(&self, other: &Self) -> Ordering {
+        let value_order = self.cmp_value(other);
+        if value_order != Ordering::Equal {
+            return value_order;
+        }
+
+        if self.category() == Category::Zero {
+            match (self.sign(), other.sign()) {
+                (true, false) => return Ordering::Less,
+                (false, true) => return Ordering::Greater,
+                _ => {}
+            }
+        }
+
+        if self.category() == Category::Infinity {
+            return Ordering::Equal;
+        }
+
+        // Same sign, same numeric value (zero or normal), different cohorts: break the tie by
+        // exponent
+        let exp_order = self.exponent().cmp(&other.exponent());
+        if self.sign() {
+            exp_order.reverse()
+        } else {
+            exp_order
+        }
+    }
+
+    /// Returns true if `self` and `other` belong to the same quantum (cohort), per the IEEE-754
+    /// `sameQuantum` operation. NaNs are mutually `same_quantum`, as are infinities of either sign;
+    /// neither category is ever `same_quantum` with the other or with a finite value
+    pub fn same_quantum(&self, other: &Self) -> bool {
+        match (self.category(), other.category()) {
+            (Category::Nan, Category::Nan) => true,
+            (Category::Infinity, Category::Infinity) => true,
+            (Category::Nan, _) | (_, Category::Nan) => false,
+            (Category::Infinity, _) | (_, Category::Infinity) => false,
+            _ => self.exponent() == other.exponent(),
+        }
+    }
+}
+
+/// Compares the magnitude of two `Normal` values by aligning their exponents: first by adjusted
+/// exponent (order of magnitude), then, for equal adjusted exponents, by scaling the
+/// larger-exponent coeffecient up to compare digit-for-digit against the other
+fn compare_normals<S: DecimalSemantics>(a: &DecimalImpl<S>, b: &DecimalImpl<S>) -> Ordering {
+    let c1 = a.coeffecient();
+    let c2 = b.coeffecient();
+    let e1 = i32::from(a.exponent());
+    let e2 = i32::from(b.exponent());
+    let len1 = c1.to_string().len() as i32;
+    let len2 = c2.to_string().len() as i32;
+
+    let adjusted1 = e1 + len1 - 1;
+    let adjusted2 = e2 + len2 - 1;
+
+    match adjusted1.cmp(&adjusted2) {
+        Ordering::Equal => {
+            if e1 <= e2 {
+                c1.cmp(&scale_up(c2, (e2 - e1) as u32))
+            } else {
+                scale_up(c1, (e1 - e2) as u32).cmp(&c2)
+            }
+        }
+        order => order,
+    }
+}
+
+/// Multiplies `value` by `10^digits`
+fn scale_up<T: DecimalStorage>(mut value: T, digits: u32) -> T {
+    for _ in 0..digits {
+        value = value * T::from(10);
+    }
+    value
+}
+
+impl<S: DecimalSemantics> PartialEq for DecimalImpl<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl<S: DecimalSemantics> PartialOrd for DecimalImpl<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+
+        Some(self.cmp_value(other))
+    }
+}
+
+impl<S: DecimalSemantics> Decimal for DecimalImpl<S> {
+    type Coeffecient = S::Coeffecient;
+    type Exponent = i16;
+
+    fn new() -> Self {
+        DecimalImpl { bits: S::Storage::from(0) }
+    }
+
+    fn sign(&self) -> bool {
+        (self.bits >> (S::BITS - 1)).low_u32() > 0
+    }
+
+    fn set_sign(&mut self, sign: bool) {
+        let shift = S::BITS - 1;
+        let mask = S::Storage::from(1) << shift;
+        self.bits = self.bits & !mask;
+        if sign {
+            self.bits = self.bits | mask;
+        }
+    }
+
+    fn exponent(&self) -> Self::Exponent {
+        // Infinities and NaNs have no exponent
+        if self.is_special() {
+            return 0;
+        }
+
+        let exp_msb = self.exponent_msb();
+        let exp_cont = self.exponent_cont();
+
+        // Encoded exponent combines the combination field's 2-bit MSB with the continuation
+        let encoded_exp = (i32::from(exp_msb) << Self::exponent_cont_bits()) + exp_cont as i32;
+
+        // Adjust encoded exponent with bias
+        let exp = encoded_exp - i32::from(S::EXPONENT_BIAS);
+
+        exp as i16
+    }
+
+    fn set_exponent(&mut self, exp: Self::Exponent) -> Result<(), DecimalStorageError> {
+        if self.is_special() {
+            return Err(DecimalStorageError::ValueIsSpecial);
+        }
+
+        let exponent_min = 1 - S::EXPONENT_MAX;
+
+        if exp > Self::max_quantum_exponent() {
+            return Err(DecimalStorageError::ExponentTooLarge);
+        }
+
+        if exp < exponent_min {
+            return Err(DecimalStorageError::ExponentTooSmall);
+        }
+
+        // Add the exponent bias
+        let encoded_exp = (i32::from(exp) + i32::from(S::EXPONENT_BIAS)) as u32;
+
+        // Set new exponent msb in combination field
+        let exp_msb = (encoded_exp >> Self::exponent_cont_bits()) as u8;
+        let coeff_msd = self.coeffecient_msd();
+        let combination_field = encode_combination_field(exp_msb, coeff_msd);
+        self.set_combination_field(combination_field);
+
+        // Set new exponent continuation bits
+        let cont_mask = (1u32 << Self::exponent_cont_bits()) - 1;
+        self.set_exponent_cont(encoded_exp & cont_mask);
+
+        Ok(())
+    }
+
+    fn coeffecient(&self) -> Self::Coeffecient {
+        // Infinity has no coeffecient; a NaN's trailing bits are a diagnostic payload, not a DPD
+        // coeffecient
+        if self.is_special() {
+            if self.combination_field() == NAN_COMBINATION {
+                let mask = Self::low_mask(Self::signaling_shift());
+                return Self::Coeffecient::from(self.bits & mask);
+            }
+            return Self::Coeffecient::from(0);
+        }
+
+        let msd = Self::Coeffecient::from(u32::from(self.coeffecient_msd()));
+        let coeff_cont = Self::Coeffecient::from(self.coeffecient_cont());
+
+        // Unpack the trailing digits from DPD, then fold in the BCD-encoded leading digit
+        let trailing = digits_from_dpd(coeff_cont, Self::declets());
+
+        let mut scale = Self::Coeffecient::from(1);
+        for _ in 0..Self::declets() {
+            scale = scale * Self::Coeffecient::from(1_000);
+        }
+
+        msd * scale + trailing
+    }
+
+    fn set_coeffecient(&mut self, coeff: Self::Coeffecient) -> Result<(), DecimalStorageError> {
+        if self.is_special() {
+            return Err(DecimalStorageError::ValueIsSpecial);
+        }
+
+        if coeff > Self::coeffecient_max() {
+            return Err(DecimalStorageError::CoeffecientTooLarge);
+        }
+
+        let mut scale = Self::Coeffecient::from(1);
+        for _ in 0..Self::declets() {
+            scale = scale * Self::Coeffecient::from(1_000);
+        }
+
+        let msd = (coeff / scale).low_u32() as u8;
+        let trailing = coeff % scale;
+
+        // Fold the MSD together with the current exponent MSB into the combination field
+        let exp_msb = self.exponent_msb();
+        let combination_field = encode_combination_field(exp_msb, msd);
+        self.set_combination_field(combination_field);
+
+        let coeff_cont = dpd_from_digits(trailing, Self::declets());
+        self.set_coeffecient_cont(S::Storage::from(coeff_cont));
+
+        Ok(())
+    }
+
+    fn category(&self) -> Category {
+        let comb = self.combination_field();
+
+        if comb >> 1 == INFINITY_COMBINATION >> 1 {
+            return if comb == NAN_COMBINATION {
+                Category::Nan
+            } else {
+                Category::Infinity
+            };
+        }
+
+        if self.coeffecient() == Self::Coeffecient::from(0) {
+            Category::Zero
+        } else {
+            Category::Normal
+        }
+    }
+
+    fn infinity(sign: bool) -> Self {
+        let mut d = Self::new();
+        d.set_sign(sign);
+        d.set_combination_field(INFINITY_COMBINATION);
+        d
+    }
+
+    fn nan() -> Self {
+        let mut d = Self::new();
+        d.set_combination_field(NAN_COMBINATION);
+        d
+    }
+
+    fn signaling_nan() -> Self {
+        let mut d = Self::nan();
+        let mask = S::Storage::from(1) << Self::signaling_shift();
+        d.bits = d.bits | mask;
+        d
+    }
+
+    fn is_signaling(&self) -> bool {
+        let mask = S::Storage::from(1) << Self::signaling_shift();
+        self.is_nan() && (self.bits & mask) != S::Storage::from(0)
+    }
+
+    fn to_scientific_string(&self) -> String {
+        let sign = if self.sign() { "-" } else { "" };
+
+        match self.category() {
+            Category::Nan => {
+                let kind = if self.is_signaling() { "sNaN" } else { "NaN" };
+                format!("{}{}", sign, kind)
+            }
+            Category::Infinity => format!("{}Infinity", sign),
+            Category::Zero | Category::Normal => {
+                let digits = self.coeffecient().to_string();
+                let exp = i32::from(self.exponent());
+                let adjusted = exp + digits.len() as i32 - 1;
+
+                if exp <= 0 && adjusted >= -6 {
+                    plain_notation(sign, &digits, exp)
+                } else {
+                    scientific_notation(sign, &digits, adjusted)
+                }
+            }
+        }
+    }
+
+    fn to_engineering_string(&self) -> String {
+        if !self.is_finite() {
+            return self.to_scientific_string();
+        }
+
+        let sign = if self.sign() { "-" } else { "" };
+        let digits = self.coeffecient().to_string();
+        let exp = i32::from(self.exponent());
+        let adjusted = exp + digits.len() as i32 - 1;
+
+        if exp <= 0 && adjusted >= -6 {
+            return plain_notation(sign, &digits, exp);
+        }
+
+        // Shift the decimal point so 1-3 digits remain before it and the printed exponent is a
+        // multiple of three
+        let shift = adjusted.rem_euclid(3);
+        let eng_exp = adjusted - shift;
+        let digits_before = (shift + 1) as usize;
+
+        let mut digits = digits;
+        while digits.len() < digits_before {
+            digits.push('0');
+        }
+
+        let (int_part, frac_part) = digits.split_at(digits_before);
+        let exp_sign = if eng_exp >= 0 { "+" } else { "-" };
+        if frac_part.is_empty() {
+            format!("{}{}E{}{}", sign, int_part, exp_sign, eng_exp.abs())
+        } else {
+            format!(
+                "{}{}.{}E{}{}",
+                sign, int_part, frac_part, exp_sign, eng_exp.abs()
+            )
+        }
+    }
+
+    fn from_string(s: &str) -> Result<Self, ParseError> {
+        let negative = match s.as_bytes().first() {
+            Some(b'+') => false,
+            Some(b'-') => true,
+            _ => false,
+        };
+        let s = if s.starts_with('+') || s.starts_with('-') {
+            &s[1..]
+        } else {
+            s
+        };
+
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        match s.to_ascii_lowercase().as_str() {
+            "infinity" | "inf" => return Ok(Self::infinity(negative)),
+            "nan" => {
+                let mut d = Self::nan();
+                d.set_sign(negative);
+                return Ok(d);
+            }
+            "snan" => {
+                let mut d = Self::signaling_nan();
+                d.set_sign(negative);
+                return Ok(d);
+            }
+            _ => {}
+        }
+
+        let (mantissa, exp_part) = match s.find(['e', 'E']) {
+            Some(idx) => (&s[..idx], Some(&s[idx + 1..])),
+            None => (s, None),
+        };
+
+        let explicit_exp: i32 = match exp_part {
+            Some(exp_str) => {
+                let (exp_negative, exp_digits) = match exp_str.as_bytes().first() {
+                    Some(b'+') => (false, &exp_str[1..]),
+                    Some(b'-') => (true, &exp_str[1..]),
+                    _ => (false, exp_str),
+                };
+
+                if exp_digits.is_empty() || !exp_digits.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(ParseError::InvalidExponent);
+                }
+
+                let magnitude: i32 = exp_digits.parse().map_err(|_| ParseError::OutOfRange)?;
+                if exp_negative {
+                    -magnitude
+                } else {
+                    magnitude
+                }
+            }
+            None => 0,
+        };
+
+        let (int_part, frac_part) = match mantissa.find('.') {
+            Some(idx) => (&mantissa[..idx], &mantissa[idx + 1..]),
+            None => (mantissa, ""),
+        };
+
+        if int_part.is_empty() && frac_part.is_empty() {
+            return Err(ParseError::InvalidDigit);
+        }
+        if !int_part.bytes().all(|b| b.is_ascii_digit())
+            || !frac_part.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(ParseError::InvalidDigit);
+        }
+
+        let mut digits = String::with_capacity(int_part.len() + frac_part.len());
+        digits.push_str(int_part);
+        digits.push_str(frac_part);
+        if digits.is_empty() {
+            digits.push('0');
+        }
+
+        let coeff: S::Coeffecient = digits.parse().map_err(|_| ParseError::OutOfRange)?;
+        if coeff > Self::coeffecient_max() {
+            return Err(ParseError::OutOfRange);
+        }
+
+        let total_exp = explicit_exp - frac_part.len() as i32;
+        let exp = i16::try_from(total_exp).map_err(|_| ParseError::OutOfRange)?;
+
+        let mut d = Self::new();
+        d.set_sign(negative);
+        d.set_exponent(exp).map_err(|_| ParseError::OutOfRange)?;
+        d.set_coeffecient(coeff).map_err(|_| ParseError::OutOfRange)?;
+        Ok(d)
+    }
+
+    fn from_u8(num: u8) -> Self {
+        let mut d = Self::new();
+        d.set_coeffecient(Self::Coeffecient::from(u32::from(num))).unwrap();
+        d
+    }
+
+    fn from_u16(num: u16) -> Self {
+        let mut d = Self::new();
+        d.set_coeffecient(Self::Coeffecient::from(u32::from(num))).unwrap();
+        d
+    }
+
+    fn from_u32_rounded(num: u32, round: Round) -> StatusAnd<Self> {
+        let mut coeff = Self::Coeffecient::from(num);
+        let mut exp: i16 = 0;
+        let mut loss = Loss::ExactlyZero;
+
+        let ten = Self::Coeffecient::from(10);
+        let two = Self::Coeffecient::from(2);
+
+        // Drop digits (least significant first) until the coeffecient fits, folding each one
+        // into the running loss so the final digit dropped determines the rounding decision
+        while coeff > Self::coeffecient_max() {
+            let digit = (coeff % ten).low_u32();
+            coeff = coeff / ten;
+            exp += 1;
+            loss = loss.combine_digit(digit);
+        }
+
+        let mut status = Status {
+            inexact: loss != Loss::ExactlyZero,
+            overflow: false,
+        };
+
+        let retained_is_odd = (coeff % two).low_u32() == 1;
+        if loss.round_up(round, false, retained_is_odd) {
+            coeff = coeff + Self::Coeffecient::from(1);
+            // Rounding up can carry the coeffecient to one digit too many
+            if coeff > Self::coeffecient_max() {
+                coeff = coeff / ten;
+                exp += 1;
+            }
+        }
+
+        if exp > Self::max_quantum_exponent() {
+            status.overflow = true;
+            exp = Self::max_quantum_exponent();
+        }
+
+        let mut d = Self::new();
+        d.set_exponent(exp).unwrap();
+        d.set_coeffecient(coeff).unwrap();
+
+        StatusAnd { status, value: d }
+    }
+
+    fn from_u8_checked(num: u8) -> Option<Self> {
+        Some(Self::from_u8(num))
+    }
+}
+
+/// Formats `digits` (with the implied decimal point `exp` places from its right edge) without an
+/// exponent, as used when the decimal spec's "to-scientific-string" conversion picks plain form
+fn plain_notation(sign: &str, digits: &str, exp: i32) -> String {
+    if exp == 0 {
+        return format!("{}{}", sign, digits);
+    }
+
+    let point = digits.len() as i32 + exp;
+    if point <= 0 {
+        let zeros = "0".repeat((-point) as usize);
+        format!("{}0.{}{}", sign, zeros, digits)
+    } else {
+        let (int_part, frac_part) = digits.split_at(point as usize);
+        format!("{}{}.{}", sign, int_part, frac_part)
+    }
+}
+
+/// Formats `digits` with a single leading digit followed by an `E` exponent, as used when the
+/// decimal spec's "to-scientific-string" conversion picks scientific form
+fn scientific_notation(sign: &str, digits: &str, adjusted_exp: i32) -> String {
+    let (first, rest) = digits.split_at(1);
+    let exp_sign = if adjusted_exp >= 0 { "+" } else { "-" };
+    if rest.is_empty() {
+        format!("{}{}E{}{}", sign, first, exp_sign, adjusted_exp.abs())
+    } else {
+        format!(
+            "{}{}.{}E{}{}",
+            sign,
+            first,
+            rest,
+            exp_sign,
+            adjusted_exp.abs()
+        )
+    }
+}
+
+impl<S: DecimalSemantics> fmt::Display for DecimalImpl<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_scientific_string())
+    }
+}
+
+impl<S: DecimalSemantics> FromStr for DecimalImpl<S> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_string(s)
+    }
+}
@@ -0,0 +1,138 @@
+//! Densely Packed Decimal (DPD) encoding, used to pack 3 decimal digits into 10 bits for the
+//! coeffecient continuation fields of the IEEE-754 decimal formats.
+use crate::int::DecimalStorage;
+
+/// Decodes a single 10-bit DPD declet into its 3 decimal digits `(hundreds, tens, units)`
+fn digits_from_declet<T: DecimalStorage>(declet: T) -> (T, T, T) {
+    let bit = |i: u32| (declet >> i) & T::from(1);
+    let group = |shift: u32| (declet >> shift) & T::from(0x7);
+
+    let b3 = bit(3);
+    let b2 = bit(2);
+    let b1 = bit(1);
+    let b4 = bit(4);
+    let b0 = bit(0);
+
+    let zero = T::from(0);
+    let eight = T::from(8);
+
+    if b3 == zero {
+        // All three digits are in the range 0-7
+        return (group(7), group(4), group(0));
+    }
+
+    if b2 == zero && b1 == zero {
+        // Units digit is 8 or 9
+        return (group(7), group(4), eight + b0);
+    }
+
+    if b2 == zero && b1 != zero {
+        // Tens digit is 8 or 9
+        return (group(7), eight + b0, group(4));
+    }
+
+    if b2 != zero && b1 == zero {
+        // Hundreds digit is 8 or 9
+        return (eight + b0, group(7), group(4));
+    }
+
+    // Two or three digits are 8 or 9; the remaining bits pick which
+    match (b4 != zero, b0 != zero) {
+        (false, false) => (eight + bit(6), eight + bit(5), group(7)), // hundreds & tens large
+        (false, true) => (eight + bit(6), group(7), eight + bit(5)), // hundreds & units large
+        (true, false) => (group(7), eight + bit(6), eight + bit(5)), // tens & units large
+        (true, true) => (eight + bit(7), eight + bit(6), eight + bit(5)), // all three large
+    }
+}
+
+/// Encodes 3 decimal digits (each 0-9) into a single 10-bit DPD declet
+fn declet_from_digits<T: DecimalStorage>(hundreds: T, tens: T, units: T) -> T {
+    let eight = T::from(8);
+    let small = |d: T| d & T::from(0x7);
+    let lsb = |d: T| d & T::from(0x1);
+
+    let large = |d: T| d >= eight;
+
+    match (large(hundreds), large(tens), large(units)) {
+        (false, false, false) => {
+            (small(hundreds) << 7) | (small(tens) << 4) | small(units)
+        }
+        (false, false, true) => {
+            (small(hundreds) << 7) | (small(tens) << 4) | T::from(0x8) | lsb(units)
+        }
+        (false, true, false) => {
+            (small(hundreds) << 7) | (small(units) << 4) | T::from(0xa) | lsb(tens)
+        }
+        (true, false, false) => {
+            (small(tens) << 7) | (small(units) << 4) | T::from(0xc) | lsb(hundreds)
+        }
+        // Two or three digits are 8 or 9; bits 4 and 0 pick which, alongside the fixed "111"
+        // combination bits 3-1
+        (true, true, false) => {
+            (small(units) << 7) | (lsb(hundreds) << 6) | (lsb(tens) << 5) | T::from(0x0e)
+        }
+        (true, false, true) => {
+            (small(tens) << 7) | (lsb(hundreds) << 6) | (lsb(units) << 5) | T::from(0x0f)
+        }
+        (false, true, true) => {
+            (small(hundreds) << 7) | (lsb(tens) << 6) | (lsb(units) << 5) | T::from(0x1e)
+        }
+        (true, true, true) => {
+            T::from(0x300) | (lsb(hundreds) << 7) | (lsb(tens) << 6) | (lsb(units) << 5) | T::from(0x1f)
+        }
+    }
+}
+
+/// Decodes `declets` worth of DPD-packed bits (each declet 10 bits, most significant first) into
+/// their decimal value
+pub(crate) fn digits_from_dpd<T: DecimalStorage>(bits: T, declets: u32) -> T {
+    let mut value = T::from(0);
+
+    for i in 0..declets {
+        let shift = (declets - 1 - i) * 10;
+        let declet = (bits >> shift) & T::from(0x3ff);
+        let (h, t, u) = digits_from_declet(declet);
+        value = value * T::from(1_000) + h * T::from(100) + t * T::from(10) + u;
+    }
+
+    value
+}
+
+/// Encodes a decimal value with up to `declets * 3` digits into `declets` worth of DPD-packed
+/// bits (each declet 10 bits, most significant first)
+pub(crate) fn dpd_from_digits<T: DecimalStorage>(mut digits: T, declets: u32) -> T {
+    let mut bits = T::from(0);
+
+    for i in 0..declets {
+        let shift = i * 10;
+        let hundreds = (digits / T::from(100)) % T::from(10);
+        let tens = (digits / T::from(10)) % T::from(10);
+        let units = digits % T::from(10);
+        digits = digits / T::from(1_000);
+
+        bits = bits | (declet_from_digits(hundreds, tens, units) << shift);
+    }
+
+    bits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declet_round_trip() {
+        for digits in 0u32..1000 {
+            let declet = dpd_from_digits(digits, 1);
+            assert_eq!(digits_from_dpd(declet, 1), digits);
+        }
+    }
+
+    #[test]
+    fn test_multi_declet_round_trip() {
+        for digits in [0u32, 5, 999, 123_456, 999_999] {
+            let bits = dpd_from_digits(digits, 2);
+            assert_eq!(digits_from_dpd(bits, 2), digits);
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! Error types shared across the decimal storage implementations
+
+/// Errors that can occur when reading or writing the fields of a decimal's backing storage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimalStorageError {
+    /// The supplied exponent is greater than the representable maximum
+    ExponentTooLarge,
+
+    /// The supplied exponent is less than the representable minimum
+    ExponentTooSmall,
+
+    /// The supplied coeffecient (significand) is greater than the representable maximum
+    CoeffecientTooLarge,
+
+    /// The value is an Infinity or NaN, which has no exponent or coeffecient to set
+    ValueIsSpecial,
+}
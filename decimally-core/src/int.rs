@@ -1,4 +1,6 @@
-use core::ops::{Add, Div, Mul, Neg, Rem, Shl, Shr, Sub};
+use core::fmt::Display;
+use core::ops::{Add, BitAnd, BitOr, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
+use core::str::FromStr;
 
 pub trait Integer<Rhs = Self, Output = Self>:
     Sized
@@ -47,3 +49,41 @@ impl SignedInteger for i32 {}
 impl SignedInteger for i64 {}
 impl SignedInteger for i128 {}
 impl SignedInteger for isize {}
+
+/// An unsigned integer wide enough to back a decimal format's bit pattern or decoded coeffecient,
+/// with the extra conversions the width-generic decimal implementation needs to synthesize small
+/// constants (digit groupings, shift amounts) and bit-manipulate the stored pattern at each width
+pub trait DecimalStorage:
+    UnsignedInteger
+    + Copy
+    + From<u32>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + Not<Output = Self>
+    + Display
+    + FromStr
+{
+    /// Truncating conversion to `u32`, used to read bit-fields (the combination field, exponent
+    /// continuation) that are always narrow enough to fit regardless of the overall storage width
+    fn low_u32(self) -> u32;
+}
+
+impl DecimalStorage for u32 {
+    fn low_u32(self) -> u32 {
+        self
+    }
+}
+
+impl DecimalStorage for u64 {
+    fn low_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+impl DecimalStorage for u128 {
+    fn low_u32(self) -> u32 {
+        self as u32
+    }
+}
@@ -0,0 +1,12 @@
+//! Core types and traits for `decimally`'s IEEE-754 decimal floating point implementations
+
+pub mod decimal;
+pub mod decimal128;
+pub mod decimal32;
+pub mod decimal64;
+pub mod error;
+pub mod int;
+pub mod semantics;
+
+mod decimal_impl;
+mod dpd;
@@ -0,0 +1,75 @@
+//! Per-width parameters for the IEEE-754 decimal interchange formats, modeled on rustc_apfloat's
+//! `Semantics` trait. [`crate::decimal_impl::DecimalImpl`] computes its bit-field layout and DPD
+//! split from these constants, so the decimal32/64/128 formats share one implementation.
+use crate::int::DecimalStorage;
+
+/// The constants and backing integer types that distinguish one decimal interchange format from
+/// another
+pub trait DecimalSemantics {
+    /// Total width of the format, in bits
+    const BITS: u32;
+
+    /// Number of significant decimal digits the coeffecient can hold
+    const PRECISION: usize;
+
+    /// Added to the true exponent to produce the non-negative encoded exponent that's stored
+    const EXPONENT_BIAS: i16;
+
+    /// Maximum adjusted exponent (IEEE-754 `emax`). The largest storable quantum exponent is
+    /// smaller by `PRECISION - 1`
+    const EXPONENT_MAX: i16;
+
+    /// Bit position of the 5-bit combination field, counting from the value's least significant
+    /// bit
+    const COMBINATION_SHIFT: u32;
+
+    /// Unsigned integer type wide enough to hold the format's full bit pattern
+    type Storage: DecimalStorage + From<Self::Coeffecient>;
+
+    /// Unsigned integer type wide enough to hold the decoded coeffecient (up to `10^PRECISION - 1`).
+    /// Always convertible to and from `Storage`, since every current format's coeffecient occupies
+    /// the same width as its overall storage
+    type Coeffecient: DecimalStorage + From<Self::Storage>;
+}
+
+/// Semantics for the 32-bit decimal interchange format
+pub struct Decimal32Semantics;
+
+impl DecimalSemantics for Decimal32Semantics {
+    const BITS: u32 = 32;
+    const PRECISION: usize = 7;
+    const EXPONENT_BIAS: i16 = 101;
+    const EXPONENT_MAX: i16 = 96;
+    const COMBINATION_SHIFT: u32 = 26;
+
+    type Storage = u32;
+    type Coeffecient = u32;
+}
+
+/// Semantics for the 64-bit decimal interchange format
+pub struct Decimal64Semantics;
+
+impl DecimalSemantics for Decimal64Semantics {
+    const BITS: u32 = 64;
+    const PRECISION: usize = 16;
+    const EXPONENT_BIAS: i16 = 398;
+    const EXPONENT_MAX: i16 = 384;
+    const COMBINATION_SHIFT: u32 = 58;
+
+    type Storage = u64;
+    type Coeffecient = u64;
+}
+
+/// Semantics for the 128-bit decimal interchange format
+pub struct Decimal128Semantics;
+
+impl DecimalSemantics for Decimal128Semantics {
+    const BITS: u32 = 128;
+    const PRECISION: usize = 34;
+    const EXPONENT_BIAS: i16 = 6176;
+    const EXPONENT_MAX: i16 = 6144;
+    const COMBINATION_SHIFT: u32 = 122;
+
+    type Storage = u128;
+    type Coeffecient = u128;
+}